@@ -0,0 +1,36 @@
+/// Error types recognized as impossible by the methods of this crate.
+///
+/// This trait only exists with the `blanket_impl` feature enabled, where it
+/// takes the place of `Uninhabited` in the bounds of the implementations
+/// provided by this crate. It is implemented for all types implementing
+/// `Uninhabited`, as well as all types that can be converted into the never
+/// type `!`, and cannot be implemented otherwise:
+///
+/// ```compile_fail
+/// struct Oops;
+///
+/// impl unwrap_infallible::Never for Oops {}
+/// ```
+pub trait Never: sealed::Sealed {}
+
+impl<E: sealed::Sealed> Never for E {}
+
+mod sealed {
+    use crate::Uninhabited;
+
+    /// The union of the bounds accepted by `Never`.
+    ///
+    /// Being a marker trait, it admits both blanket implementations
+    /// even for the types that satisfy both bounds.
+    #[marker]
+    pub trait Sealed {}
+
+    impl<E: Uninhabited> Sealed for E {}
+
+    impl<E: Into<!>> Sealed for E {}
+}
+
+/// Converts a value of an impossible type into a value of any type.
+pub(crate) fn absurd<E: Never, T>(_never: E) -> T {
+    unreachable!("a value of an uninhabited type was encountered")
+}
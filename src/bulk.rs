@@ -0,0 +1,139 @@
+//! Conversion of containers of infallible results into containers
+//! of their success values.
+//!
+//! When a `Result<T, E>` with uninhabited `E` has the same size and
+//! alignment as `T`, the `T` value of the `Ok` variant necessarily occupies
+//! the whole of the result's storage at offset 0, so the memory holding
+//! the results can be reused to hold the success values. Each element is
+//! still passed through `UnwrapInfallible` when converting in place, which
+//! compiles to nothing for genuinely uninhabited error types, but keeps
+//! the conversion sound with any implementation of `Uninhabited`.
+
+use crate::{Never, UnwrapInfallible};
+
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::slice;
+
+struct SameLayout<T, E>(PhantomData<(T, E)>);
+
+impl<T, E> SameLayout<T, E> {
+    const HOLDS: bool = mem::size_of::<Result<T, E>>() == mem::size_of::<T>()
+        && mem::align_of::<Result<T, E>>() == mem::align_of::<T>();
+}
+
+/// Replaces `len` results starting at `ptr` with their success values.
+///
+/// If `Uninhabited::absurd` panics, the memory is left in an unspecified
+/// state, so the caller must take care to leak the storage rather than
+/// drop its contents.
+///
+/// # Safety
+///
+/// `SameLayout::<T, E>::HOLDS` must be true, and `ptr` must be valid for
+/// reads and writes of `len` initialized results.
+unsafe fn convert_in_place<T, E: Never>(ptr: *mut Result<T, E>, len: usize) {
+    for i in 0..len {
+        let slot = ptr.add(i);
+        let v = ptr::read(slot).unwrap_infallible();
+        ptr::write(slot.cast::<T>(), v);
+    }
+}
+
+impl<T, E: Never, const N: usize> UnwrapInfallible for [Result<T, E>; N] {
+    type Ok = [T; N];
+    fn unwrap_infallible(self) -> [T; N] {
+        if SameLayout::<T, E>::HOLDS {
+            let mut array = ManuallyDrop::new(self);
+            unsafe {
+                convert_in_place(array.as_mut_ptr(), N);
+                ptr::read((&*array as *const [Result<T, E>; N]).cast::<[T; N]>())
+            }
+        } else {
+            self.map(UnwrapInfallible::unwrap_infallible)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E: Never> UnwrapInfallible for Vec<Result<T, E>> {
+    type Ok = Vec<T>;
+    fn unwrap_infallible(self) -> Vec<T> {
+        if SameLayout::<T, E>::HOLDS {
+            let mut vec = ManuallyDrop::new(self);
+            let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+            unsafe {
+                convert_in_place(ptr, len);
+                Vec::from_raw_parts(ptr.cast::<T>(), len, cap)
+            }
+        } else {
+            self.into_iter()
+                .map(UnwrapInfallible::unwrap_infallible)
+                .collect()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E: Never> UnwrapInfallible for Box<[Result<T, E>]> {
+    type Ok = Box<[T]>;
+    fn unwrap_infallible(self) -> Box<[T]> {
+        if SameLayout::<T, E>::HOLDS {
+            let len = self.len();
+            let slice = Box::into_raw(self);
+            unsafe {
+                convert_in_place(slice.cast::<Result<T, E>>(), len);
+                Box::from_raw(slice as *mut [T])
+            }
+        } else {
+            self.into_vec().unwrap_infallible().into_boxed_slice()
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, E: Never> UnwrapInfallible for Box<Result<T, E>> {
+    type Ok = Box<T>;
+    fn unwrap_infallible(self) -> Box<T> {
+        if SameLayout::<T, E>::HOLDS {
+            let ptr = Box::into_raw(self);
+            unsafe {
+                convert_in_place(ptr, 1);
+                Box::from_raw(ptr.cast::<T>())
+            }
+        } else {
+            Box::new((*self).unwrap_infallible())
+        }
+    }
+}
+
+/// Borrows the success values of a slice of infallible results as a slice,
+/// or clones them if the layout of the results differs from that of the
+/// success values.
+///
+/// Whether the slice can be borrowed depends on the layout of the results,
+/// which is only known after monomorphization, so the `Cow` returned
+/// requires `T: Clone` for the case when it cannot. To access
+/// the success values of a slice of results whose success type does not
+/// implement `Clone`, iterate over references to them with
+/// `slice.iter().unwrap_infallible_each()`.
+#[cfg(feature = "alloc")]
+impl<'a, T: Clone, E: Never> UnwrapInfallible for &'a [Result<T, E>] {
+    type Ok = Cow<'a, [T]>;
+    fn unwrap_infallible(self) -> Cow<'a, [T]> {
+        if SameLayout::<T, E>::HOLDS {
+            for r in self {
+                r.unwrap_infallible();
+            }
+            let slice = unsafe { slice::from_raw_parts(self.as_ptr().cast::<T>(), self.len()) };
+            Cow::Borrowed(slice)
+        } else {
+            Cow::Owned(self.iter().map(|r| r.unwrap_infallible().clone()).collect())
+        }
+    }
+}
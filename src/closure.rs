@@ -0,0 +1,83 @@
+//! Lifting of plain closures into closures returning infallible results.
+
+use core::convert::Infallible;
+
+/// Lifts a closure with no arguments into one returning an infallible result.
+///
+/// This is useful with APIs that only accept fallible initializers.
+/// The result of such an API can then be unwrapped with `UnwrapInfallible`.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use unwrap_infallible::{ok_fn0, UnwrapInfallible};
+///
+/// fn get_or_try_init<E>(
+///     slot: &mut Option<u32>,
+///     init: impl FnOnce() -> Result<u32, E>,
+/// ) -> Result<u32, E> {
+///     match *slot {
+///         Some(v) => Ok(v),
+///         None => {
+///             let v = init()?;
+///             *slot = Some(v);
+///             Ok(v)
+///         }
+///     }
+/// }
+///
+/// let mut slot = None;
+/// let v = get_or_try_init(&mut slot, ok_fn0(|| 42)).unwrap_infallible();
+/// assert_eq!(v, 42);
+/// ```
+pub fn ok_fn0<R, F>(f: F) -> impl FnOnce() -> Result<R, Infallible>
+where
+    F: FnOnce() -> R,
+{
+    move || Ok(f())
+}
+
+/// Lifts a closure with one argument into one returning an infallible result.
+///
+/// The returned closure implements `Fn`, so it can be passed to APIs that
+/// call it by shared reference, possibly from multiple threads.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use unwrap_infallible::{ok_fn1, UnwrapInfallible};
+///
+/// let doubled: Result<Vec<u32>, Infallible> = [1u32, 2, 3].iter().map(ok_fn1(|x| x * 2)).collect();
+/// assert_eq!(doubled.unwrap_infallible(), [2, 4, 6]);
+/// ```
+pub fn ok_fn1<A, R, F>(f: F) -> impl Fn(A) -> Result<R, Infallible>
+where
+    F: Fn(A) -> R,
+{
+    move |a| Ok(f(a))
+}
+
+/// Lifts a closure with two arguments into one returning an infallible
+/// result.
+///
+/// Like with `ok_fn1`, the returned closure implements `Fn`.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::{ok_fn2, UnwrapInfallible};
+///
+/// let sum = [1, 2, 3]
+///     .iter()
+///     .try_fold(0, ok_fn2(|acc, x| acc + x))
+///     .unwrap_infallible();
+/// assert_eq!(sum, 6);
+/// ```
+pub fn ok_fn2<A, B, R, F>(f: F) -> impl Fn(A, B) -> Result<R, Infallible>
+where
+    F: Fn(A, B) -> R,
+{
+    move |a, b| Ok(f(a, b))
+}
@@ -0,0 +1,75 @@
+//! Implementations of `Uninhabited` for types composed of uninhabited types.
+
+use crate::Uninhabited;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+macro_rules! impl_for_tuples {
+    ($($name:ident)*) => {
+        /// A tuple is uninhabited if its first element is.
+        impl<Z: Uninhabited, $($name),*> Uninhabited for (Z, $($name,)*) {
+            fn absurd<T>(self) -> T {
+                self.0.absurd()
+            }
+        }
+    };
+}
+
+impl_for_tuples!();
+impl_for_tuples!(A);
+impl_for_tuples!(A B);
+impl_for_tuples!(A B C);
+impl_for_tuples!(A B C D);
+impl_for_tuples!(A B C D E);
+impl_for_tuples!(A B C D E F);
+impl_for_tuples!(A B C D E F G);
+impl_for_tuples!(A B C D E F G H);
+impl_for_tuples!(A B C D E F G H I);
+impl_for_tuples!(A B C D E F G H I J);
+impl_for_tuples!(A B C D E F G H I J K);
+
+macro_rules! impl_for_arrays {
+    ($($n:literal)+) => {
+        $(
+            /// A non-empty array is uninhabited if its element type is.
+            impl<E: Uninhabited> Uninhabited for [E; $n] {
+                fn absurd<T>(self) -> T {
+                    let [never, ..] = self;
+                    never.absurd()
+                }
+            }
+        )+
+    };
+}
+
+impl_for_arrays!(
+    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16
+    17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+);
+
+/// A result is uninhabited if both of its variants are.
+impl<A: Uninhabited, B: Uninhabited> Uninhabited for Result<A, B> {
+    fn absurd<T>(self) -> T {
+        match self {
+            Ok(never) => never.absurd(),
+            Err(never) => never.absurd(),
+        }
+    }
+}
+
+/// An `Either` is uninhabited if both of its variants are.
+#[cfg(feature = "either")]
+impl<A: Uninhabited, B: Uninhabited> Uninhabited for either::Either<A, B> {
+    fn absurd<T>(self) -> T {
+        either::for_both!(self, never => never.absurd())
+    }
+}
+
+/// A box is uninhabited if its content is.
+#[cfg(feature = "alloc")]
+impl<E: Uninhabited> Uninhabited for Box<E> {
+    fn absurd<T>(self) -> T {
+        (*self).absurd()
+    }
+}
@@ -0,0 +1,86 @@
+//! Statically infallible conversions.
+
+use crate::{Never, UnwrapInfallible};
+
+use core::convert::TryFrom;
+use core::str::FromStr;
+
+/// Conversion of a value into another type through an infallible
+/// `TryFrom` implementation.
+///
+/// This trait is implemented for all sized types. Its method is only
+/// available for target types whose `TryFrom` error type is uninhabited,
+/// so a conversion that is lossless today fails to compile once
+/// the conversion becomes fallible.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::ConvertInfallible;
+///
+/// let a = 42u8;
+/// let b: u64 = a.convert_infallible();
+/// assert_eq!(b, 42);
+/// ```
+///
+/// A conversion that can fail is rejected:
+///
+/// ```compile_fail
+/// use unwrap_infallible::ConvertInfallible;
+///
+/// let a = 42u64;
+/// let b: u8 = a.convert_infallible();
+/// ```
+pub trait ConvertInfallible: Sized {
+    /// Converts the value into `U`.
+    ///
+    /// This is equivalent to `U::try_from(self).unwrap_infallible()`.
+    fn convert_infallible<U>(self) -> U
+    where
+        U: TryFrom<Self>,
+        U::Error: Never,
+    {
+        U::try_from(self).unwrap_infallible()
+    }
+}
+
+impl<T> ConvertInfallible for T {}
+
+/// Parsing of string slices into types that can always be parsed.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::ParseInfallible;
+///
+/// let s: String = "it's always sunny!".parse_infallible();
+/// assert_eq!(s, "it's always sunny!");
+/// ```
+///
+/// Parsing that can fail is rejected:
+///
+/// ```compile_fail
+/// use unwrap_infallible::ParseInfallible;
+///
+/// let n: u32 = "42".parse_infallible();
+/// ```
+pub trait ParseInfallible {
+    /// Parses the string into `F`.
+    ///
+    /// This is equivalent to `str::parse` followed by `unwrap_infallible`,
+    /// and only compiles when `F::Err` is uninhabited.
+    fn parse_infallible<F>(&self) -> F
+    where
+        F: FromStr,
+        F::Err: Never;
+}
+
+impl ParseInfallible for str {
+    fn parse_infallible<F>(&self) -> F
+    where
+        F: FromStr,
+        F::Err: Never,
+    {
+        self.parse::<F>().unwrap_infallible()
+    }
+}
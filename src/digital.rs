@@ -0,0 +1,114 @@
+//! Extensions of `embedded-hal` digital I/O pins that cannot fail.
+
+use crate::{Never, UnwrapInfallible};
+
+use embedded_hal::digital::{InputPin, OutputPin, PinState, StatefulOutputPin};
+
+/// Extension trait for output pins with an uninhabited error type.
+///
+/// # Example
+///
+/// ```
+/// use embedded_hal::digital::{ErrorType, OutputPin};
+/// use std::convert::Infallible;
+/// use unwrap_infallible::InfallibleOutputPin;
+///
+/// struct Led(bool);
+///
+/// impl ErrorType for Led {
+///     type Error = Infallible;
+/// }
+///
+/// impl OutputPin for Led {
+///     fn set_low(&mut self) -> Result<(), Infallible> {
+///         self.0 = false;
+///         Ok(())
+///     }
+///     fn set_high(&mut self) -> Result<(), Infallible> {
+///         self.0 = true;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut led = Led(false);
+/// led.set_high_infallible();
+/// assert!(led.0);
+/// ```
+pub trait InfallibleOutputPin: OutputPin {
+    /// Drives the pin low.
+    fn set_low_infallible(&mut self)
+    where
+        Self::Error: Never,
+    {
+        self.set_low().unwrap_infallible()
+    }
+
+    /// Drives the pin high.
+    fn set_high_infallible(&mut self)
+    where
+        Self::Error: Never,
+    {
+        self.set_high().unwrap_infallible()
+    }
+
+    /// Drives the pin high or low depending on the provided value.
+    fn set_state_infallible(&mut self, state: PinState)
+    where
+        Self::Error: Never,
+    {
+        self.set_state(state).unwrap_infallible()
+    }
+}
+
+impl<P: OutputPin + ?Sized> InfallibleOutputPin for P {}
+
+/// Extension trait for output pins with an uninhabited error type
+/// that can read back their driven state.
+pub trait InfallibleStatefulOutputPin: StatefulOutputPin {
+    /// Is the pin in drive high mode?
+    fn is_set_high_infallible(&mut self) -> bool
+    where
+        Self::Error: Never,
+    {
+        self.is_set_high().unwrap_infallible()
+    }
+
+    /// Is the pin in drive low mode?
+    fn is_set_low_infallible(&mut self) -> bool
+    where
+        Self::Error: Never,
+    {
+        self.is_set_low().unwrap_infallible()
+    }
+
+    /// Toggles the pin state.
+    fn toggle_infallible(&mut self)
+    where
+        Self::Error: Never,
+    {
+        self.toggle().unwrap_infallible()
+    }
+}
+
+impl<P: StatefulOutputPin + ?Sized> InfallibleStatefulOutputPin for P {}
+
+/// Extension trait for input pins with an uninhabited error type.
+pub trait InfallibleInputPin: InputPin {
+    /// Is the input pin high?
+    fn is_high_infallible(&mut self) -> bool
+    where
+        Self::Error: Never,
+    {
+        self.is_high().unwrap_infallible()
+    }
+
+    /// Is the input pin low?
+    fn is_low_infallible(&mut self) -> bool
+    where
+        Self::Error: Never,
+    {
+        self.is_low().unwrap_infallible()
+    }
+}
+
+impl<P: InputPin + ?Sized> InfallibleInputPin for P {}
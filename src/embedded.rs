@@ -0,0 +1,102 @@
+//! Extensions of `embedded-io` readers and writers that cannot fail.
+
+use crate::{absurd, Never, UnwrapInfallible};
+
+use core::convert::Infallible;
+use embedded_io::{Read, ReadExactError, Write};
+
+/// Extension trait for `embedded-io` writers with an uninhabited
+/// error type.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use embedded_io::{ErrorType, Write};
+/// use unwrap_infallible::InfallibleEmbeddedWrite;
+///
+/// struct Buf(Vec<u8>);
+///
+/// impl ErrorType for Buf {
+///     type Error = Infallible;
+/// }
+///
+/// impl Write for Buf {
+///     fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+///         self.0.extend_from_slice(buf);
+///         Ok(buf.len())
+///     }
+///     fn flush(&mut self) -> Result<(), Infallible> {
+///         Ok(())
+///     }
+/// }
+///
+/// let mut buf = Buf(Vec::new());
+/// buf.write_all_infallible(b"it's always sunny!");
+/// buf.flush_infallible();
+/// assert_eq!(buf.0, b"it's always sunny!");
+/// ```
+pub trait InfallibleEmbeddedWrite: Write {
+    /// Writes a buffer, returning how many bytes were written.
+    fn write_infallible(&mut self, buf: &[u8]) -> usize
+    where
+        Self::Error: Never,
+    {
+        self.write(buf).unwrap_infallible()
+    }
+
+    /// Writes the entire buffer.
+    fn write_all_infallible(&mut self, buf: &[u8])
+    where
+        Self::Error: Never,
+    {
+        self.write_all(buf).unwrap_infallible()
+    }
+
+    /// Flushes the output stream.
+    fn flush_infallible(&mut self)
+    where
+        Self::Error: Never,
+    {
+        self.flush().unwrap_infallible()
+    }
+}
+
+impl<W: Write + ?Sized> InfallibleEmbeddedWrite for W {}
+
+/// Extension trait for `embedded-io` readers with an uninhabited
+/// error type.
+pub trait InfallibleEmbeddedRead: Read {
+    /// Reads some bytes into the buffer, returning how many bytes were read.
+    fn read_infallible(&mut self, buf: &mut [u8]) -> usize
+    where
+        Self::Error: Never,
+    {
+        self.read(buf).unwrap_infallible()
+    }
+
+    /// Reads the exact number of bytes required to fill the buffer.
+    ///
+    /// The reader cannot fail, but it can still reach the end of the input,
+    /// in which case `ReadExactError::UnexpectedEof` is returned.
+    fn read_exact_infallible(&mut self, buf: &mut [u8]) -> Result<(), ReadExactError<Infallible>>
+    where
+        Self::Error: Never,
+    {
+        self.read_exact(buf).map_err(eof_only)
+    }
+}
+
+impl<R: Read + ?Sized> InfallibleEmbeddedRead for R {}
+
+/// Narrows a `ReadExactError` of a reader that cannot fail to
+/// the end-of-input condition.
+///
+/// This is also used for `embedded-io-async`, which re-exports
+/// `ReadExactError` from `embedded-io`.
+pub(crate) fn eof_only<E: Never>(e: ReadExactError<E>) -> ReadExactError<Infallible> {
+    match e {
+        ReadExactError::UnexpectedEof => ReadExactError::UnexpectedEof,
+        ReadExactError::Other(never) => absurd(never),
+    }
+}
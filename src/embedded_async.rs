@@ -0,0 +1,71 @@
+//! Extensions of `embedded-io-async` readers and writers that cannot fail.
+
+use crate::embedded::eof_only;
+use crate::{Never, UnwrapInfallible};
+
+use core::convert::Infallible;
+use embedded_io_async::{Read, ReadExactError, Write};
+
+/// Extension trait for `embedded-io-async` writers with an uninhabited
+/// error type.
+///
+/// This is the asynchronous counterpart of `InfallibleEmbeddedWrite`.
+#[allow(async_fn_in_trait)]
+pub trait InfallibleEmbeddedAsyncWrite: Write {
+    /// Writes a buffer, returning how many bytes were written.
+    async fn write_infallible(&mut self, buf: &[u8]) -> usize
+    where
+        Self::Error: Never,
+    {
+        self.write(buf).await.unwrap_infallible()
+    }
+
+    /// Writes the entire buffer.
+    async fn write_all_infallible(&mut self, buf: &[u8])
+    where
+        Self::Error: Never,
+    {
+        self.write_all(buf).await.unwrap_infallible()
+    }
+
+    /// Flushes the output stream.
+    async fn flush_infallible(&mut self)
+    where
+        Self::Error: Never,
+    {
+        self.flush().await.unwrap_infallible()
+    }
+}
+
+impl<W: Write + ?Sized> InfallibleEmbeddedAsyncWrite for W {}
+
+/// Extension trait for `embedded-io-async` readers with an uninhabited
+/// error type.
+///
+/// This is the asynchronous counterpart of `InfallibleEmbeddedRead`.
+#[allow(async_fn_in_trait)]
+pub trait InfallibleEmbeddedAsyncRead: Read {
+    /// Reads some bytes into the buffer, returning how many bytes were read.
+    async fn read_infallible(&mut self, buf: &mut [u8]) -> usize
+    where
+        Self::Error: Never,
+    {
+        self.read(buf).await.unwrap_infallible()
+    }
+
+    /// Reads the exact number of bytes required to fill the buffer.
+    ///
+    /// The reader cannot fail, but it can still reach the end of the input,
+    /// in which case `ReadExactError::UnexpectedEof` is returned.
+    async fn read_exact_infallible(
+        &mut self,
+        buf: &mut [u8],
+    ) -> Result<(), ReadExactError<Infallible>>
+    where
+        Self::Error: Never,
+    {
+        self.read_exact(buf).await.map_err(eof_only)
+    }
+}
+
+impl<R: Read + ?Sized> InfallibleEmbeddedAsyncRead for R {}
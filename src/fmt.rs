@@ -0,0 +1,158 @@
+//! Writing formatted text to buffers that cannot fail.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Writing to text buffers whose writes cannot fail.
+///
+/// This trait is implemented only for writers whose `write_str` never
+/// returns an error, such as `String`. Code writing through it fails
+/// to compile when the writer is later changed to one that can fail.
+///
+/// The macros `write_infallible!` and `writeln_infallible!` provide
+/// the formatting syntax of `write!` and `writeln!` for these writers.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use unwrap_infallible::InfallibleFmtWrite;
+///
+/// let mut s = String::new();
+/// s.write_str_infallible("it's ");
+/// s.write_fmt_infallible(format_args!("always {}", "sunny"));
+/// s.write_char_infallible('!');
+/// assert_eq!(s, "it's always sunny!");
+/// # }
+/// ```
+pub trait InfallibleFmtWrite: fmt::Write {
+    /// Writes a string slice.
+    ///
+    /// This is the infallible counterpart of `fmt::Write::write_str`.
+    fn write_str_infallible(&mut self, s: &str);
+
+    /// Writes a character.
+    ///
+    /// This is the infallible counterpart of `fmt::Write::write_char`.
+    fn write_char_infallible(&mut self, c: char) {
+        self.write_str_infallible(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Writes formatted output.
+    ///
+    /// This is the infallible counterpart of `fmt::Write::write_fmt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error.
+    /// This indicates a bug in that implementation, as the writer itself
+    /// cannot fail.
+    fn write_fmt_infallible(&mut self, args: fmt::Arguments<'_>) {
+        write_fmt_with(|s| self.write_str_infallible(s), args);
+    }
+}
+
+/// Writes formatted output by passing the formatted pieces to `write_str`,
+/// which cannot fail.
+///
+/// # Panics
+///
+/// Panics if a formatting trait implementation returns an error, as
+/// the error cannot have come from `write_str`.
+pub(crate) fn write_fmt_with(write_str: impl FnMut(&str), args: fmt::Arguments<'_>) {
+    struct Adapter<F>(F);
+
+    impl<F: FnMut(&str)> fmt::Write for Adapter<F> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            (self.0)(s);
+            Ok(())
+        }
+    }
+
+    if fmt::write(&mut Adapter(write_str), args).is_err() {
+        panic!("a formatting trait implementation returned an error to an infallible writer");
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl InfallibleFmtWrite for String {
+    fn write_str_infallible(&mut self, s: &str) {
+        self.push_str(s);
+    }
+
+    fn write_char_infallible(&mut self, c: char) {
+        self.push(c);
+    }
+}
+
+impl<W: InfallibleFmtWrite + ?Sized> InfallibleFmtWrite for &mut W {
+    fn write_str_infallible(&mut self, s: &str) {
+        (**self).write_str_infallible(s);
+    }
+
+    fn write_char_infallible(&mut self, c: char) {
+        (**self).write_char_infallible(c);
+    }
+
+    fn write_fmt_infallible(&mut self, args: fmt::Arguments<'_>) {
+        (**self).write_fmt_infallible(args);
+    }
+}
+
+/// Writes formatted data into a writer that cannot fail.
+///
+/// This macro is the infallible counterpart of `write!`. It calls
+/// the `write_fmt_infallible` method of the writer, so it can be used
+/// with both `InfallibleFmtWrite` and `InfallibleWrite` implementations,
+/// which need to be in scope. The macro evaluates to `()`.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use unwrap_infallible::{write_infallible, InfallibleFmtWrite};
+///
+/// let mut s = String::new();
+/// write_infallible!(s, "it's always {}!", "sunny");
+/// assert_eq!(s, "it's always sunny!");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! write_infallible {
+    ($dst:expr, $($arg:tt)*) => {
+        $dst.write_fmt_infallible(::core::format_args!($($arg)*))
+    };
+}
+
+/// Writes formatted data into a writer that cannot fail,
+/// appending a newline.
+///
+/// This macro is the infallible counterpart of `writeln!`.
+/// See `write_infallible!` for details.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use unwrap_infallible::{writeln_infallible, InfallibleFmtWrite};
+///
+/// let mut s = String::new();
+/// writeln_infallible!(s, "it's always {}!", "sunny");
+/// writeln_infallible!(s);
+/// assert_eq!(s, "it's always sunny!\n\n");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! writeln_infallible {
+    ($dst:expr $(,)?) => {
+        $crate::write_infallible!($dst, "\n")
+    };
+    ($dst:expr, $($arg:tt)*) => {
+        $dst.write_fmt_infallible(::core::format_args!(
+            "{}\n",
+            ::core::format_args!($($arg)*)
+        ))
+    };
+}
@@ -0,0 +1,69 @@
+//! Adapter for futures resolving to infallible results.
+
+use crate::UnwrapInfallible;
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// Extension trait for futures with an infallible output.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use unwrap_infallible::InfallibleFutureExt;
+///
+/// async fn always_sunny() -> Result<String, Infallible> {
+///     Ok("it's always sunny!".into())
+/// }
+///
+/// async fn forecast() -> String {
+///     always_sunny().unwrap_infallible().await
+/// }
+/// ```
+pub trait InfallibleFutureExt: Future + Sized
+where
+    Self::Output: UnwrapInfallible,
+{
+    /// Wraps the future into one that resolves to the unwrapped output
+    /// of this future.
+    fn unwrap_infallible(self) -> UnwrapInfallibleFuture<Self> {
+        UnwrapInfallibleFuture { inner: self }
+    }
+}
+
+impl<F> InfallibleFutureExt for F
+where
+    F: Future,
+    F::Output: UnwrapInfallible,
+{
+}
+
+/// Future for the `InfallibleFutureExt::unwrap_infallible` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct UnwrapInfallibleFuture<F> {
+    inner: F,
+}
+
+impl<F> UnwrapInfallibleFuture<F> {
+    /// Consumes the adapter, returning the underlying future.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl_project_inner!(UnwrapInfallibleFuture<F>);
+
+impl<F> Future for UnwrapInfallibleFuture<F>
+where
+    F: Future,
+    F::Output: UnwrapInfallible,
+{
+    type Output = <F::Output as UnwrapInfallible>::Ok;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().poll(cx).unwrap_infallible()
+    }
+}
@@ -0,0 +1,76 @@
+//! Writing to I/O sinks that cannot fail.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use std::io;
+
+/// Writing to byte sinks whose writes cannot fail.
+///
+/// This trait is implemented only for writers that never return an error,
+/// such as in-memory buffers. Code writing through it fails to compile
+/// when the writer is later changed to one whose writes can fail,
+/// such as a file, rather than panicking on an `unwrap` at run time.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::InfallibleWrite;
+///
+/// let mut buf = Vec::new();
+/// buf.write_all_infallible(b"it's ");
+/// buf.write_fmt_infallible(format_args!("always {}!", "sunny"));
+/// assert_eq!(buf, b"it's always sunny!");
+/// ```
+///
+/// ```compile_fail
+/// use std::fs::File;
+/// use unwrap_infallible::InfallibleWrite;
+///
+/// let mut file = File::create("sunny.txt").unwrap();
+/// file.write_all_infallible(b"it's always sunny!");
+/// ```
+pub trait InfallibleWrite: io::Write {
+    /// Writes the entire buffer.
+    ///
+    /// This is the infallible counterpart of `io::Write::write_all`.
+    fn write_all_infallible(&mut self, buf: &[u8]);
+
+    /// Writes formatted output.
+    ///
+    /// This is the infallible counterpart of `io::Write::write_fmt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error,
+    /// like `InfallibleFmtWrite::write_fmt_infallible` does.
+    fn write_fmt_infallible(&mut self, args: fmt::Arguments<'_>) {
+        crate::fmt::write_fmt_with(|s| self.write_all_infallible(s.as_bytes()), args);
+    }
+}
+
+impl InfallibleWrite for Vec<u8> {
+    fn write_all_infallible(&mut self, buf: &[u8]) {
+        self.extend_from_slice(buf);
+    }
+}
+
+impl InfallibleWrite for VecDeque<u8> {
+    fn write_all_infallible(&mut self, buf: &[u8]) {
+        self.extend(buf);
+    }
+}
+
+impl InfallibleWrite for io::Sink {
+    fn write_all_infallible(&mut self, _buf: &[u8]) {}
+}
+
+impl<W: InfallibleWrite + ?Sized> InfallibleWrite for &mut W {
+    fn write_all_infallible(&mut self, buf: &[u8]) {
+        (**self).write_all_infallible(buf);
+    }
+
+    fn write_fmt_infallible(&mut self, args: fmt::Arguments<'_>) {
+        (**self).write_fmt_infallible(args);
+    }
+}
@@ -0,0 +1,138 @@
+//! Adapter for iterators over infallible results.
+
+use crate::UnwrapInfallible;
+
+use core::iter::{FromIterator, FusedIterator, Product, Sum};
+
+/// Extension trait for iterators over infallible results.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use unwrap_infallible::InfallibleIteratorExt;
+///
+/// let bytes = [1u8, 2, 3];
+/// let wide: Vec<u64> = bytes.iter().copied().map(u64::try_from).collect_infallible();
+/// assert_eq!(wide, [1, 2, 3]);
+///
+/// let sum: u64 = bytes.iter().copied().map(u64::try_from).sum_infallible();
+/// assert_eq!(sum, 6);
+/// ```
+pub trait InfallibleIteratorExt: Iterator + Sized
+where
+    Self::Item: UnwrapInfallible,
+{
+    /// Wraps the iterator into one that yields the unwrapped items
+    /// of this iterator.
+    fn unwrap_infallible_each(self) -> UnwrapInfallibleEach<Self> {
+        UnwrapInfallibleEach { inner: self }
+    }
+
+    /// Collects the unwrapped items into a collection.
+    ///
+    /// This is the infallible counterpart of collecting into
+    /// `Result<C, E>`.
+    fn collect_infallible<C>(self) -> C
+    where
+        C: FromIterator<<Self::Item as UnwrapInfallible>::Ok>,
+    {
+        self.unwrap_infallible_each().collect()
+    }
+
+    /// Sums the unwrapped items.
+    fn sum_infallible<S>(self) -> S
+    where
+        S: Sum<<Self::Item as UnwrapInfallible>::Ok>,
+    {
+        self.unwrap_infallible_each().sum()
+    }
+
+    /// Multiplies the unwrapped items.
+    fn product_infallible<P>(self) -> P
+    where
+        P: Product<<Self::Item as UnwrapInfallible>::Ok>,
+    {
+        self.unwrap_infallible_each().product()
+    }
+}
+
+impl<I> InfallibleIteratorExt for I
+where
+    I: Iterator,
+    I::Item: UnwrapInfallible,
+{
+}
+
+/// Iterator for the `InfallibleIteratorExt::unwrap_infallible_each` method.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UnwrapInfallibleEach<I> {
+    inner: I,
+}
+
+impl<I> UnwrapInfallibleEach<I> {
+    /// Consumes the adapter, returning the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+impl<I> Iterator for UnwrapInfallibleEach<I>
+where
+    I: Iterator,
+    I::Item: UnwrapInfallible,
+{
+    type Item = <I::Item as UnwrapInfallible>::Ok;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().unwrap_infallible()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner
+            .fold(init, move |acc, item| f(acc, item.unwrap_infallible()))
+    }
+}
+
+impl<I> DoubleEndedIterator for UnwrapInfallibleEach<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: UnwrapInfallible,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().unwrap_infallible()
+    }
+
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.inner
+            .rfold(init, move |acc, item| f(acc, item.unwrap_infallible()))
+    }
+}
+
+impl<I> ExactSizeIterator for UnwrapInfallibleEach<I>
+where
+    I: ExactSizeIterator,
+    I::Item: UnwrapInfallible,
+{
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<I> FusedIterator for UnwrapInfallibleEach<I>
+where
+    I: FusedIterator,
+    I::Item: UnwrapInfallible,
+{
+}
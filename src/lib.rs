@@ -2,7 +2,67 @@
 //!
 //! This crate provides a convenience trait `UnwrapInfallible`,
 //! adding method `unwrap_infallible` to `Result` types where an `Err` variant
-//! is statically known to never occur.
+//! is statically known to never occur. The mirror trait `UnwrapErrInfallible`
+//! provides method `unwrap_err_infallible` for results that can only
+//! be an `Err`. Both traits are also implemented for `ControlFlow`,
+//! with `Continue` taking the role of `Ok` and `Break` that of `Err`.
+//!
+//! `FlattenInfallible` removes an infallible result nested in a result
+//! that can fail. `IntoFallible` converts an infallible result into a result
+//! with any error type.
+//!
+//! `ConvertInfallible` converts any value into another type when
+//! the `TryFrom` conversion between them cannot fail. `ParseInfallible`
+//! parses string slices into types whose `FromStr` implementation
+//! cannot fail. `WidenInfallible` converts integers into wider integer types,
+//! including the conversions between pointer-sized and fixed-size integers
+//! that are lossless on the compilation target.
+//!
+//! Functions `ok_fn0`, `ok_fn1`, and `ok_fn2` lift plain closures into
+//! closures returning infallible results, so they can be passed to APIs
+//! that only accept fallible closures, and the outcome unwrapped with
+//! `unwrap_infallible`.
+//!
+//! Iterators over infallible results can be adapted to yield the success
+//! values with `InfallibleIteratorExt`, which also provides methods to
+//! collect, sum, or multiply them directly. With the `rayon` feature,
+//! `InfallibleParallelIteratorExt` does the same for parallel iterators.
+//!
+//! Futures resolving to infallible results can be adapted to resolve to
+//! the success values with `InfallibleFutureExt`. With the `futures-core`
+//! feature enabled, `InfallibleStreamExt` provides similar adapters for
+//! streams. With the `futures-sink` feature, `InfallibleSinkExt` provides
+//! methods to send into sinks that cannot fail.
+//!
+//! Error types are recognized as impossible by implementing the
+//! `Uninhabited` trait. It is implemented for `std::convert::Infallible`,
+//! and can be implemented for custom empty enums as well. With the `derive`
+//! feature enabled, the implementation can be derived with
+//! `#[derive(Uninhabited)]`. Types composed of uninhabited types, such as
+//! tuples whose first element is uninhabited, non-empty arrays, boxes,
+//! and results or `either::Either` values with both variants uninhabited,
+//! are recognized as uninhabited as well; the latter requires
+//! the `either` feature.
+//!
+//! Arrays of infallible results can be unwrapped into arrays of the success
+//! values. With the `alloc` feature enabled, the same is possible for
+//! vectors, boxed slices, boxes, and borrowed slices, reusing the memory
+//! when the results have the same layout as their success values.
+//!
+//! `InfallibleFmtWrite` provides methods to write formatted text to buffers
+//! that cannot fail, such as `String` with the `alloc` feature enabled,
+//! and the macros `write_infallible!` and `writeln_infallible!` format into
+//! them. With the `std` feature enabled, `InfallibleWrite` does the same
+//! for in-memory byte buffers and other I/O writers that cannot fail.
+//!
+//! With the `embedded-hal` feature enabled, `InfallibleOutputPin`,
+//! `InfallibleStatefulOutputPin`, and `InfallibleInputPin` provide methods
+//! to drive and read digital pins of HALs that cannot fail. With the `nb`
+//! feature, `NbInfallibleExt` converts the results of non-blocking
+//! operations that cannot fail into `Poll`, and `block_infallible!` blocks
+//! on such operations. The `embedded-io` and `embedded-io-async` features
+//! provide extension traits for readers and writers of those crates that
+//! cannot fail; the latter feature enables the former.
 //!
 //! # Example
 //!
@@ -10,10 +70,7 @@
 //! # #![cfg_attr(feature = "never_type", feature(never_type))]
 //! #
 //! use unwrap_infallible::UnwrapInfallible;
-//! # #[cfg(not(feature = "blanket_impl"))]
 //! use std::convert::Infallible;
-//! # #[cfg(feature = "blanket_impl")]
-//! # type Infallible = !;
 //!
 //! fn always_sunny() -> Result<String, Infallible> {
 //!     Ok("it's always sunny!".into())
@@ -29,9 +86,97 @@
 #![warn(missing_docs)]
 #![no_std]
 #![cfg_attr(feature = "never_type", feature(never_type))]
+#![cfg_attr(feature = "blanket_impl", feature(marker_trait_attr))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-#[cfg(not(feature = "blanket_impl"))]
 use core::convert::Infallible;
+use core::ops::ControlFlow;
+use core::pin::Pin;
+use core::task::Poll;
+
+#[cfg(feature = "derive")]
+pub use unwrap_infallible_derive::Uninhabited;
+
+// Defined in a separate file so that the unstable syntax is not parsed
+// when the feature is disabled.
+#[cfg(feature = "blanket_impl")]
+mod bridge;
+
+#[cfg(feature = "blanket_impl")]
+use bridge::absurd;
+#[cfg(feature = "blanket_impl")]
+pub use bridge::Never;
+#[cfg(not(feature = "blanket_impl"))]
+use Uninhabited as Never;
+
+/// Implements the projection of a pinned adapter to the value it wraps
+/// in its `inner` field.
+macro_rules! impl_project_inner {
+    ($adapter:ident<$inner:ident>) => {
+        impl<$inner> $adapter<$inner> {
+            fn project(self: core::pin::Pin<&mut Self>) -> core::pin::Pin<&mut $inner> {
+                // The inner value is structurally pinned: it is never moved
+                // out of a pinned adapter, and the adapter implements
+                // neither `Drop` nor `Unpin` on its own.
+                unsafe { self.map_unchecked_mut(|this| &mut this.inner) }
+            }
+        }
+    };
+}
+
+mod bulk;
+mod closure;
+mod composite;
+mod convert;
+#[cfg(feature = "embedded-hal")]
+mod digital;
+#[cfg(feature = "embedded-io")]
+mod embedded;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_async;
+mod fmt;
+mod future;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+#[cfg(feature = "nb")]
+mod nonblocking;
+#[cfg(feature = "rayon")]
+mod par_iter;
+#[cfg(feature = "futures-sink")]
+mod sink;
+#[cfg(feature = "futures-core")]
+mod stream;
+mod widen;
+
+pub use closure::{ok_fn0, ok_fn1, ok_fn2};
+pub use convert::{ConvertInfallible, ParseInfallible};
+#[cfg(feature = "embedded-hal")]
+pub use digital::{InfallibleInputPin, InfallibleOutputPin, InfallibleStatefulOutputPin};
+#[cfg(feature = "embedded-io")]
+pub use embedded::{InfallibleEmbeddedRead, InfallibleEmbeddedWrite};
+#[cfg(feature = "embedded-io-async")]
+pub use embedded_async::{InfallibleEmbeddedAsyncRead, InfallibleEmbeddedAsyncWrite};
+pub use fmt::InfallibleFmtWrite;
+pub use future::{InfallibleFutureExt, UnwrapInfallibleFuture};
+#[cfg(feature = "std")]
+pub use io::InfallibleWrite;
+pub use iter::{InfallibleIteratorExt, UnwrapInfallibleEach};
+#[cfg(feature = "nb")]
+pub use nonblocking::NbInfallibleExt;
+#[cfg(feature = "rayon")]
+pub use par_iter::InfallibleParallelIteratorExt;
+#[cfg(feature = "futures-sink")]
+pub use sink::{
+    CloseInfallible, FeedInfallible, FlushInfallible, InfallibleSinkExt, SendInfallible,
+};
+#[cfg(feature = "futures-core")]
+pub use stream::{InfallibleStreamExt, IntoTryStream, UnwrapInfallibleStream};
+pub use widen::WidenInfallible;
 
 /// Unwrapping an infallible result into its success value.
 pub trait UnwrapInfallible {
@@ -48,40 +193,324 @@ pub trait UnwrapInfallible {
     fn unwrap_infallible(self) -> Self::Ok;
 }
 
-#[cfg(feature = "blanket_impl")]
-impl<T, E: Into<!>> UnwrapInfallible for Result<T, E> {
+/// Unwrapping a result that can only fail into its error value.
+///
+/// This is the counterpart of `UnwrapInfallible` for results with an
+/// impossible `Ok` variant, such as those returned by server loops that
+/// only ever exit with an error.
+pub trait UnwrapErrInfallible {
+    /// Type of the `Err` variant of the result.
+    type Err;
+
+    /// Unwraps a result, returning the content of an `Err`.
+    ///
+    /// Unlike `Result::unwrap_err`, this method is known to never panic
+    /// on the result types it is implemented for. It serves the same purpose
+    /// as the unstable `Result::into_err` in the standard library.
+    fn unwrap_err_infallible(self) -> Self::Err;
+}
+
+/// Stripping an infallible result nested in a result that can fail.
+///
+/// Only one layer is removed: the success value of the nested result is
+/// passed through as it is, even if it is itself an infallible result.
+/// Recognizing arbitrarily deep nesting would require distinguishing
+/// result types from all other types of success values, which is not
+/// possible with trait implementations on stable Rust. Each further layer
+/// is removed with another call, and a result that cannot fail at any
+/// layer is finally unwrapped with `UnwrapInfallible`:
+///
+/// ```
+/// use std::convert::Infallible;
+/// use unwrap_infallible::{FlattenInfallible, UnwrapInfallible};
+///
+/// let r: Result<Result<Result<u32, Infallible>, Infallible>, Infallible> = Ok(Ok(Ok(42)));
+/// assert_eq!(r.flatten_infallible().flatten_infallible().unwrap_infallible(), 42);
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use std::convert::{Infallible, TryFrom};
+/// use unwrap_infallible::FlattenInfallible;
+///
+/// fn parse_byte(s: &str) -> Result<Result<u64, Infallible>, std::num::ParseIntError> {
+///     s.parse::<u8>().map(u64::try_from)
+/// }
+///
+/// assert_eq!(parse_byte("42").flatten_infallible(), Ok(42u64));
+/// ```
+pub trait FlattenInfallible {
+    /// Type of the result with the impossible layer removed.
+    type Output;
+
+    /// Unwraps the infallible value in the `Ok` variant, leaving the
+    /// outer error as it is.
+    fn flatten_infallible(self) -> Self::Output;
+}
+
+/// Widening the error type of an infallible result.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use std::io;
+/// use unwrap_infallible::IntoFallible;
+///
+/// fn run<E>(op: impl FnOnce() -> Result<u32, E>) -> Result<u32, E> {
+///     op()
+/// }
+///
+/// fn answer() -> Result<u32, Infallible> {
+///     Ok(42)
+/// }
+///
+/// let r: Result<u32, io::Error> = run(|| answer().into_fallible());
+/// assert_eq!(r.unwrap(), 42);
+/// ```
+pub trait IntoFallible {
+    /// Type of the `Ok` variant of the result.
+    type Ok;
+
+    /// Converts an infallible result into a result with any error type.
+    ///
+    /// This is a statically checked alternative to
+    /// `map_err(|e| match e {})`.
+    fn into_fallible<E>(self) -> Result<Self::Ok, E>;
+}
+
+/// Types that have no values.
+///
+/// A value of a type implementing this trait can never be constructed,
+/// so code receiving it is statically known to be unreachable.
+/// `UnwrapInfallible` is implemented for `Result` types whose `Err` variant
+/// has an uninhabited type, as well as for references to them, so
+/// implementing this trait for a type is sufficient to unwrap results
+/// by reference.
+///
+/// References to `Infallible` and `!` are uninhabited as well, which lets
+/// the results of `Result::as_ref` and `Result::as_mut` be unwrapped when
+/// the error type is one of these. For other error types, the result
+/// itself should be unwrapped by reference instead.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::{Uninhabited, UnwrapInfallible};
+///
+/// enum MyNeverToken {}
+///
+/// impl Uninhabited for MyNeverToken {
+///     fn absurd<T>(self) -> T {
+///         match self {}
+///     }
+/// }
+///
+/// let r: Result<bool, MyNeverToken> = Ok(true);
+/// assert!(r.unwrap_infallible());
+/// ```
+pub trait Uninhabited {
+    /// Converts a value of the uninhabited type into a value of any type.
+    ///
+    /// As no values of `Self` can exist, this method can never actually
+    /// be called.
+    fn absurd<T>(self) -> T;
+}
+
+#[cfg(feature = "never_type")]
+impl Uninhabited for ! {
+    fn absurd<T>(self) -> T {
+        self
+    }
+}
+
+#[cfg(feature = "never_type")]
+impl Uninhabited for &! {
+    fn absurd<T>(self) -> T {
+        *self
+    }
+}
+
+#[cfg(feature = "never_type")]
+impl Uninhabited for &mut ! {
+    fn absurd<T>(self) -> T {
+        *self
+    }
+}
+
+impl Uninhabited for Infallible {
+    fn absurd<T>(self) -> T {
+        match self {}
+    }
+}
+
+impl Uninhabited for &Infallible {
+    fn absurd<T>(self) -> T {
+        match *self {}
+    }
+}
+
+impl Uninhabited for &mut Infallible {
+    fn absurd<T>(self) -> T {
+        match *self {}
+    }
+}
+
+#[cfg(not(feature = "blanket_impl"))]
+fn absurd<E: Never, T>(never: E) -> T {
+    never.absurd()
+}
+
+/// Diverges on a reference to a value of an uninhabited type.
+///
+/// The value cannot be moved out of the reference to call
+/// `Uninhabited::absurd`, but no such reference can exist either.
+fn absurd_ref<E: Never, T>(_never: &E) -> T {
+    unreachable!("a reference to a value of an uninhabited type was encountered")
+}
+
+impl<T, E: Never> UnwrapInfallible for Result<T, E> {
     type Ok = T;
     fn unwrap_infallible(self) -> T {
+        self.unwrap_or_else(absurd)
+    }
+}
+
+/// Accessing the success value of an infallible result by reference.
+impl<'a, T, E: Never> UnwrapInfallible for &'a Result<T, E> {
+    type Ok = &'a T;
+    fn unwrap_infallible(self) -> &'a T {
         match self {
             Ok(v) => v,
-            Err(e) => e.into(),
+            Err(never) => absurd_ref(never),
         }
     }
 }
 
-#[cfg(all(feature = "never_type", not(feature = "blanket_impl")))]
-impl<T> UnwrapInfallible for Result<T, !> {
-    type Ok = T;
-    fn unwrap_infallible(self) -> T {
-        self.unwrap_or_else(|never| never)
+/// Accessing the success value of an infallible result by
+/// mutable reference.
+impl<'a, T, E: Never> UnwrapInfallible for &'a mut Result<T, E> {
+    type Ok = &'a mut T;
+    fn unwrap_infallible(self) -> &'a mut T {
+        match self {
+            Ok(v) => v,
+            Err(never) => absurd_ref(never),
+        }
     }
 }
 
-#[cfg(not(feature = "blanket_impl"))]
-impl<T> UnwrapInfallible for Result<T, Infallible> {
+/// Projecting a pinned infallible result to its pinned success value.
+impl<'a, T, E: Never> UnwrapInfallible for Pin<&'a mut Result<T, E>> {
+    type Ok = Pin<&'a mut T>;
+    fn unwrap_infallible(self) -> Pin<&'a mut T> {
+        // The content of a pinned `Result` is never moved out of it,
+        // so pinning is structural like it is for `Option::as_pin_mut`.
+        unsafe { Pin::new_unchecked(self.get_unchecked_mut().unwrap_infallible()) }
+    }
+}
+
+/// Unwrapping an infallible value wrapped in an `Option`.
+///
+/// An `Option<Result<T, Infallible>>` is unwrapped into `Option<T>`.
+impl<R: UnwrapInfallible> UnwrapInfallible for Option<R> {
+    type Ok = Option<R::Ok>;
+    fn unwrap_infallible(self) -> Option<R::Ok> {
+        self.map(UnwrapInfallible::unwrap_infallible)
+    }
+}
+
+/// Unwrapping the infallible output of a completed poll.
+impl<R: UnwrapInfallible> UnwrapInfallible for Poll<R> {
+    type Ok = Poll<R::Ok>;
+    fn unwrap_infallible(self) -> Poll<R::Ok> {
+        self.map(UnwrapInfallible::unwrap_infallible)
+    }
+}
+
+impl<R: UnwrapInfallible, E> FlattenInfallible for Result<R, E> {
+    type Output = Result<R::Ok, E>;
+    fn flatten_infallible(self) -> Result<R::Ok, E> {
+        self.map(UnwrapInfallible::unwrap_infallible)
+    }
+}
+
+/// Extracting the `Continue` value from a `ControlFlow` that cannot break.
+impl<B: Never, C> UnwrapInfallible for ControlFlow<B, C> {
+    type Ok = C;
+    fn unwrap_infallible(self) -> C {
+        match self {
+            ControlFlow::Continue(c) => c,
+            ControlFlow::Break(never) => absurd(never),
+        }
+    }
+}
+
+/// Extracting the `Break` value from a `ControlFlow` that cannot continue.
+impl<B, C: Never> UnwrapErrInfallible for ControlFlow<B, C> {
+    type Err = B;
+    fn unwrap_err_infallible(self) -> B {
+        match self {
+            ControlFlow::Continue(never) => absurd(never),
+            ControlFlow::Break(b) => b,
+        }
+    }
+}
+
+impl<T, E: Never> IntoFallible for Result<T, E> {
     type Ok = T;
-    fn unwrap_infallible(self) -> T {
-        self.unwrap_or_else(|never| match never {})
+    fn into_fallible<F>(self) -> Result<T, F> {
+        self.map_err(absurd)
+    }
+}
+
+macro_rules! impl_for_tuples {
+    ($($name:ident)+) => {
+        /// Unwrapping a tuple of infallible results into a tuple
+        /// of their success values.
+        impl<$($name: UnwrapInfallible),+> UnwrapInfallible for ($($name,)+) {
+            type Ok = ($($name::Ok,)+);
+            #[allow(non_snake_case)]
+            fn unwrap_infallible(self) -> Self::Ok {
+                let ($($name,)+) = self;
+                ($($name.unwrap_infallible(),)+)
+            }
+        }
+    };
+}
+
+impl_for_tuples!(A);
+impl_for_tuples!(A B);
+impl_for_tuples!(A B C);
+impl_for_tuples!(A B C D);
+impl_for_tuples!(A B C D E);
+impl_for_tuples!(A B C D E F);
+impl_for_tuples!(A B C D E F G);
+impl_for_tuples!(A B C D E F G H);
+impl_for_tuples!(A B C D E F G H I);
+impl_for_tuples!(A B C D E F G H I J);
+impl_for_tuples!(A B C D E F G H I J K);
+impl_for_tuples!(A B C D E F G H I J K L);
+
+impl<T: Never, E> UnwrapErrInfallible for Result<T, E> {
+    type Err = E;
+    fn unwrap_err_infallible(self) -> E {
+        match self {
+            Ok(never) => absurd(never),
+            Err(e) => e,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::UnwrapInfallible;
+    use super::{
+        ConvertInfallible, FlattenInfallible, IntoFallible, ParseInfallible, UnwrapErrInfallible,
+        UnwrapInfallible,
+    };
 
-    // Hmm, Infallible is not Into<!> yet
-    #[cfg(not(feature = "blanket_impl"))]
     #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn with_infallible() {
         use core::convert::TryFrom;
 
@@ -90,10 +519,140 @@ mod tests {
         assert_eq!(a, 42u64);
     }
 
+    #[test]
+    fn convert() {
+        let a: u64 = 42u8.convert_infallible();
+        assert_eq!(a, 42);
+        let s: &str = "sunny";
+        let s = s.convert_infallible::<&str>();
+        assert_eq!(s, "sunny");
+    }
+
+    #[test]
+    fn parse() {
+        use core::convert::Infallible;
+        use core::str::FromStr;
+
+        #[derive(Debug, PartialEq)]
+        struct Len(usize);
+
+        impl FromStr for Len {
+            type Err = Infallible;
+            fn from_str(s: &str) -> Result<Self, Infallible> {
+                Ok(Len(s.len()))
+            }
+        }
+
+        let len: Len = "sunny".parse_infallible();
+        assert_eq!(len, Len(5));
+    }
+
+    #[test]
+    fn by_reference() {
+        use core::convert::Infallible;
+
+        let mut r: Result<u32, Infallible> = Ok(42);
+        assert_eq!(*(&r).unwrap_infallible(), 42);
+        assert_eq!(*r.as_ref().unwrap_infallible(), 42);
+        *(&mut r).unwrap_infallible() += 1;
+        *r.as_mut().unwrap_infallible() += 1;
+        assert_eq!(r.unwrap_infallible(), 44);
+    }
+
+    #[test]
+    fn pinned() {
+        use core::convert::Infallible;
+        use core::pin::Pin;
+
+        let mut r: Result<u32, Infallible> = Ok(42);
+        let pinned: Pin<&mut u32> = Pin::new(&mut r).unwrap_infallible();
+        *pinned.get_mut() += 1;
+        assert_eq!(r.unwrap_infallible(), 43);
+    }
+
+    #[test]
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    fn tuple() {
+        use core::convert::{Infallible, TryFrom};
+
+        let (a, b, c) = (
+            u64::try_from(1u8),
+            u64::try_from(2u32),
+            Ok::<_, Infallible>("three"),
+        )
+            .unwrap_infallible();
+        assert_eq!((a, b, c), (1u64, 2u64, "three"));
+    }
+
+    #[test]
+    fn nested() {
+        use core::convert::Infallible;
+
+        let r: Option<Result<u32, Infallible>> = Some(Ok(42));
+        assert_eq!(r.unwrap_infallible(), Some(42));
+        let r: Result<Option<u32>, Infallible> = Ok(Some(42));
+        assert_eq!(r.unwrap_infallible(), Some(42));
+        let r: Result<Result<u32, Infallible>, Infallible> = Ok(Ok(42));
+        assert_eq!(r.flatten_infallible().unwrap_infallible(), 42);
+        let r: Result<Option<Result<u32, Infallible>>, &str> = Ok(Some(Ok(42)));
+        assert_eq!(r.flatten_infallible(), Ok(Some(42)));
+        let r: Result<Result<u32, Infallible>, &str> = Err("oops");
+        assert_eq!(r.flatten_infallible(), Err("oops"));
+    }
+
+    #[test]
+    fn widen_error() {
+        use core::convert::Infallible;
+
+        let r: Result<u32, Infallible> = Ok(42);
+        let r: Result<u32, &str> = r.into_fallible();
+        assert_eq!(r, Ok(42));
+    }
+
+    #[test]
+    fn control_flow() {
+        use core::convert::Infallible;
+        use core::ops::ControlFlow;
+
+        let sum = [1, 2, 3]
+            .iter()
+            .try_fold(0, |acc, x| ControlFlow::<Infallible, _>::Continue(acc + x))
+            .unwrap_infallible();
+        assert_eq!(sum, 6);
+
+        fn search() -> ControlFlow<u32, Infallible> {
+            let mut x = 0;
+            loop {
+                x += 1;
+                if x * x > 50 {
+                    return ControlFlow::Break(x);
+                }
+            }
+        }
+
+        let found = search().unwrap_err_infallible();
+        assert_eq!(found, 8);
+    }
+
+    #[test]
+    fn err_with_infallible() {
+        use core::convert::Infallible;
+
+        let r: Result<Infallible, &str> = Err("shutdown");
+        assert_eq!(r.unwrap_err_infallible(), "shutdown");
+    }
+
     #[cfg(feature = "never_type")]
     #[test]
     fn with_never_type() {
         let r: Result<bool, !> = Ok(true);
         assert!(r.unwrap_infallible());
     }
+
+    #[cfg(feature = "never_type")]
+    #[test]
+    fn err_with_never_type() {
+        let r: Result<!, bool> = Err(true);
+        assert!(r.unwrap_err_infallible());
+    }
 }
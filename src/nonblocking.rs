@@ -0,0 +1,81 @@
+//! Support for `nb` non-blocking operations that cannot fail.
+
+use crate::{absurd, Never};
+
+use core::task::Poll;
+
+/// Conversion of the result of a non-blocking operation that cannot fail.
+///
+/// This trait is implemented for `nb::Result<T, E>` with an uninhabited
+/// error type, where the only outcomes are a value or `WouldBlock`.
+/// The macro `block_infallible!` uses it to block until the operation
+/// completes.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use std::task::Poll;
+/// use unwrap_infallible::NbInfallibleExt;
+///
+/// let r: nb::Result<u8, Infallible> = Err(nb::Error::WouldBlock);
+/// assert_eq!(r.into_poll_infallible(), Poll::Pending);
+/// let r: nb::Result<u8, Infallible> = Ok(42);
+/// assert_eq!(r.into_poll_infallible(), Poll::Ready(42));
+/// ```
+pub trait NbInfallibleExt {
+    /// Type of the value produced by the operation.
+    type Ok;
+
+    /// Converts the result into `Poll`, with `WouldBlock` mapped
+    /// to `Pending`.
+    fn into_poll_infallible(self) -> Poll<Self::Ok>;
+}
+
+impl<T, E: Never> NbInfallibleExt for nb::Result<T, E> {
+    type Ok = T;
+    fn into_poll_infallible(self) -> Poll<T> {
+        match self {
+            Ok(v) => Poll::Ready(v),
+            Err(nb::Error::WouldBlock) => Poll::Pending,
+            Err(nb::Error::Other(never)) => absurd(never),
+        }
+    }
+}
+
+/// Turns a non-blocking expression that cannot fail into a blocking
+/// operation.
+///
+/// This is the infallible counterpart of `nb::block!`: the expression
+/// is evaluated repeatedly while it returns `nb::Error::WouldBlock`,
+/// and the macro evaluates to the produced value rather than to a result.
+/// The error type of the expression must be uninhabited.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::Infallible;
+/// use unwrap_infallible::block_infallible;
+///
+/// let mut ticks = 0;
+/// let mut wait = || -> nb::Result<u32, Infallible> {
+///     ticks += 1;
+///     if ticks < 3 {
+///         Err(nb::Error::WouldBlock)
+///     } else {
+///         Ok(ticks)
+///     }
+/// };
+/// assert_eq!(block_infallible!(wait()), 3);
+/// ```
+#[macro_export]
+macro_rules! block_infallible {
+    ($e:expr) => {
+        loop {
+            if let ::core::task::Poll::Ready(v) = $crate::NbInfallibleExt::into_poll_infallible($e)
+            {
+                break v;
+            }
+        }
+    };
+}
@@ -0,0 +1,93 @@
+//! Adapters for rayon parallel iterators over infallible results.
+
+use crate::{Never, UnwrapInfallible};
+
+use rayon::iter::{Map, ParallelIterator, TryFold};
+
+/// Extension trait for rayon parallel iterators producing or folding
+/// infallible results.
+///
+/// # Example
+///
+/// ```
+/// use rayon::prelude::*;
+/// use std::convert::{Infallible, TryFrom};
+/// use unwrap_infallible::InfallibleParallelIteratorExt;
+///
+/// let wide: Vec<u64> = vec![1u8, 2, 3]
+///     .into_par_iter()
+///     .map(u64::try_from)
+///     .unwrap_infallible_each()
+///     .collect();
+/// assert_eq!(wide, [1, 2, 3]);
+///
+/// let sum = wide
+///     .par_iter()
+///     .map(|&x| Ok::<_, Infallible>(x))
+///     .try_reduce_infallible(|| 0, |a, b| Ok(a + b));
+/// assert_eq!(sum, 6);
+/// ```
+pub trait InfallibleParallelIteratorExt: ParallelIterator {
+    /// Maps the items of this iterator to their unwrapped values.
+    ///
+    /// The returned iterator preserves indexing of this iterator.
+    #[allow(clippy::type_complexity)]
+    fn unwrap_infallible_each(
+        self,
+    ) -> Map<Self, fn(Self::Item) -> <Self::Item as UnwrapInfallible>::Ok>
+    where
+        Self::Item: UnwrapInfallible,
+        <Self::Item as UnwrapInfallible>::Ok: Send,
+    {
+        self.map(UnwrapInfallible::unwrap_infallible)
+    }
+
+    /// Executes an infallible `op` for each item in parallel.
+    ///
+    /// This is the counterpart of `ParallelIterator::try_for_each` for
+    /// closures returning `Result<(), E>` with uninhabited `E`.
+    fn try_for_each_infallible<E, OP>(self, op: OP)
+    where
+        OP: Fn(Self::Item) -> Result<(), E> + Sync + Send,
+        E: Never + Send,
+    {
+        self.try_for_each(op).unwrap_infallible()
+    }
+
+    /// Reduces the items of this iterator with an infallible `op`.
+    ///
+    /// This is the counterpart of `ParallelIterator::try_reduce` for
+    /// iterators over infallible results.
+    fn try_reduce_infallible<T, E, ID, OP>(self, identity: ID, op: OP) -> T
+    where
+        Self: ParallelIterator<Item = Result<T, E>>,
+        OP: Fn(T, T) -> Result<T, E> + Sync + Send,
+        ID: Fn() -> T + Sync + Send,
+        E: Never,
+    {
+        self.try_reduce(identity, op).unwrap_infallible()
+    }
+
+    /// Folds the items of this iterator with an infallible `fold_op`,
+    /// producing an iterator over the unwrapped accumulated values.
+    ///
+    /// This is the counterpart of `ParallelIterator::try_fold` for
+    /// closures returning `Result<T, E>` with uninhabited `E`.
+    #[allow(clippy::type_complexity)]
+    fn try_fold_infallible<T, E, ID, F>(
+        self,
+        identity: ID,
+        fold_op: F,
+    ) -> Map<TryFold<Self, Result<T, E>, ID, F>, fn(Result<T, E>) -> T>
+    where
+        F: Fn(T, Self::Item) -> Result<T, E> + Sync + Send,
+        ID: Fn() -> T + Sync + Send,
+        T: Send,
+        E: Never + Send,
+    {
+        self.try_fold(identity, fold_op)
+            .map(UnwrapInfallible::unwrap_infallible)
+    }
+}
+
+impl<I: ParallelIterator> InfallibleParallelIteratorExt for I {}
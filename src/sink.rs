@@ -0,0 +1,179 @@
+//! Extension of sinks that cannot fail.
+
+use crate::{Never, UnwrapInfallible};
+
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_sink::Sink;
+
+/// Extension trait for sinks with an uninhabited error type.
+///
+/// The methods return futures resolving to `()` rather than to
+/// infallible results.
+///
+/// # Example
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::sink;
+/// use unwrap_infallible::InfallibleSinkExt;
+///
+/// let mut sink = sink::drain();
+/// sink.send_infallible(42).await;
+/// sink.close_infallible().await;
+/// # });
+/// ```
+pub trait InfallibleSinkExt<Item>: Sink<Item> {
+    /// Sends an item into the sink and flushes it.
+    fn send_infallible(&mut self, item: Item) -> SendInfallible<'_, Self, Item>
+    where
+        Self: Unpin,
+        Self::Error: Never,
+    {
+        SendInfallible {
+            feed: FeedInfallible {
+                sink: self,
+                item: Some(item),
+            },
+        }
+    }
+
+    /// Sends an item into the sink without flushing it.
+    fn feed_infallible(&mut self, item: Item) -> FeedInfallible<'_, Self, Item>
+    where
+        Self: Unpin,
+        Self::Error: Never,
+    {
+        FeedInfallible {
+            sink: self,
+            item: Some(item),
+        }
+    }
+
+    /// Flushes the sink.
+    fn flush_infallible(&mut self) -> FlushInfallible<'_, Self, Item>
+    where
+        Self: Unpin,
+        Self::Error: Never,
+    {
+        FlushInfallible {
+            sink: self,
+            _item: PhantomData,
+        }
+    }
+
+    /// Closes the sink.
+    fn close_infallible(&mut self) -> CloseInfallible<'_, Self, Item>
+    where
+        Self: Unpin,
+        Self::Error: Never,
+    {
+        CloseInfallible {
+            sink: self,
+            _item: PhantomData,
+        }
+    }
+}
+
+impl<Si: Sink<Item> + ?Sized, Item> InfallibleSinkExt<Item> for Si {}
+
+/// Future for the `InfallibleSinkExt::feed_infallible` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FeedInfallible<'a, Si: ?Sized, Item> {
+    sink: &'a mut Si,
+    item: Option<Item>,
+}
+
+// The item is never pinned.
+impl<Si: Unpin + ?Sized, Item> Unpin for FeedInfallible<'_, Si, Item> {}
+
+impl<Si, Item> Future for FeedInfallible<'_, Si, Item>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Si::Error: Never,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let mut sink = Pin::new(&mut *this.sink);
+        ready!(sink.as_mut().poll_ready(cx).unwrap_infallible());
+        let item = this.item.take().expect("polled after completion");
+        sink.start_send(item).unwrap_infallible();
+        Poll::Ready(())
+    }
+}
+
+/// Future for the `InfallibleSinkExt::send_infallible` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct SendInfallible<'a, Si: ?Sized, Item> {
+    feed: FeedInfallible<'a, Si, Item>,
+}
+
+impl<Si: Unpin + ?Sized, Item> Unpin for SendInfallible<'_, Si, Item> {}
+
+impl<Si, Item> Future for SendInfallible<'_, Si, Item>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Si::Error: Never,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        if this.feed.item.is_some() {
+            ready!(Pin::new(&mut this.feed).poll(cx));
+        }
+        Pin::new(&mut *this.feed.sink)
+            .poll_flush(cx)
+            .unwrap_infallible()
+    }
+}
+
+/// Future for the `InfallibleSinkExt::flush_infallible` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FlushInfallible<'a, Si: ?Sized, Item> {
+    sink: &'a mut Si,
+    _item: PhantomData<fn(Item)>,
+}
+
+impl<Si, Item> Future for FlushInfallible<'_, Si, Item>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Si::Error: Never,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut *self.get_mut().sink)
+            .poll_flush(cx)
+            .unwrap_infallible()
+    }
+}
+
+/// Future for the `InfallibleSinkExt::close_infallible` method.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CloseInfallible<'a, Si: ?Sized, Item> {
+    sink: &'a mut Si,
+    _item: PhantomData<fn(Item)>,
+}
+
+impl<Si, Item> Future for CloseInfallible<'_, Si, Item>
+where
+    Si: Sink<Item> + Unpin + ?Sized,
+    Si::Error: Never,
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut *self.get_mut().sink)
+            .poll_close(cx)
+            .unwrap_infallible()
+    }
+}
@@ -0,0 +1,123 @@
+//! Adapters between streams of infallible results and plain streams.
+
+use crate::UnwrapInfallible;
+
+use core::convert::Infallible;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use futures_core::stream::{FusedStream, Stream};
+
+/// Extension trait for moving streams between infallible `TryStream`
+/// and plain `Stream` APIs.
+///
+/// # Example
+///
+/// ```
+/// # futures::executor::block_on(async {
+/// use futures::stream::{self, StreamExt, TryStreamExt};
+/// use std::convert::Infallible;
+/// use unwrap_infallible::InfallibleStreamExt;
+///
+/// let items = stream::iter(vec![1, 2, 3])
+///     .into_try_stream()
+///     .map_ok(|x| x * 2)
+///     .unwrap_infallible_each()
+///     .collect::<Vec<_>>()
+///     .await;
+/// assert_eq!(items, [2, 4, 6]);
+/// # });
+/// ```
+pub trait InfallibleStreamExt: Stream + Sized {
+    /// Wraps the stream into one that yields the unwrapped items
+    /// of this stream.
+    fn unwrap_infallible_each(self) -> UnwrapInfallibleStream<Self>
+    where
+        Self::Item: UnwrapInfallible,
+    {
+        UnwrapInfallibleStream { inner: self }
+    }
+
+    /// Wraps the stream into one that yields the items of this stream
+    /// as infallible results.
+    fn into_try_stream(self) -> IntoTryStream<Self> {
+        IntoTryStream { inner: self }
+    }
+}
+
+impl<S: Stream> InfallibleStreamExt for S {}
+
+/// Stream for the `InfallibleStreamExt::unwrap_infallible_each` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct UnwrapInfallibleStream<S> {
+    inner: S,
+}
+
+impl<S> UnwrapInfallibleStream<S> {
+    /// Consumes the adapter, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl_project_inner!(UnwrapInfallibleStream<S>);
+
+impl<S> Stream for UnwrapInfallibleStream<S>
+where
+    S: Stream,
+    S::Item: UnwrapInfallible,
+{
+    type Item = <S::Item as UnwrapInfallible>::Ok;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().poll_next(cx).unwrap_infallible()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> FusedStream for UnwrapInfallibleStream<S>
+where
+    S: FusedStream,
+    S::Item: UnwrapInfallible,
+{
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
+
+/// Stream for the `InfallibleStreamExt::into_try_stream` method.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct IntoTryStream<S> {
+    inner: S,
+}
+
+impl<S> IntoTryStream<S> {
+    /// Consumes the adapter, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl_project_inner!(IntoTryStream<S>);
+
+impl<S: Stream> Stream for IntoTryStream<S> {
+    type Item = Result<S::Item, Infallible>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.project().poll_next(cx).map(|item| item.map(Ok))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: FusedStream> FusedStream for IntoTryStream<S> {
+    fn is_terminated(&self) -> bool {
+        self.inner.is_terminated()
+    }
+}
@@ -0,0 +1,106 @@
+//! Lossless integer widening, including conversions that are only
+//! lossless on some targets.
+
+/// Lossless conversion of an integer into a wider integer type.
+///
+/// The standard library does not implement `From` for conversions between
+/// pointer-sized integers and fixed-size integers that would be lossy
+/// on some platforms, such as `u32` to `usize` or `usize` to `u64`.
+/// This trait is implemented for every pair of integer types where
+/// the conversion is lossless on the current `target_pointer_width`,
+/// so a conversion that is not guaranteed to be lossless on the compilation
+/// target fails to compile, rather than having to be handled with
+/// `TryFrom` and `unwrap`.
+///
+/// The method is not simply named `widen`: nightly toolchains provide
+/// inherent methods of that name on the integer types, such as `u32::widen`
+/// gated behind the unstable feature `integer_widen_truncate`, and calls
+/// to a trait method with the same name trigger the `unstable_name_collisions`
+/// lint.
+///
+/// # Example
+///
+/// ```
+/// use unwrap_infallible::WidenInfallible;
+///
+/// let len: usize = 42;
+/// let len: u64 = len.widen_infallible();
+/// assert_eq!(len, 42);
+///
+/// let n: usize = 42u16.widen_infallible();
+/// assert_eq!(n, 42);
+/// ```
+///
+/// A conversion that can lose information is rejected:
+///
+/// ```compile_fail
+/// use unwrap_infallible::WidenInfallible;
+///
+/// let n: u8 = 42u16.widen_infallible();
+/// ```
+pub trait WidenInfallible<T> {
+    /// Converts the value into the wider type `T`.
+    fn widen_infallible(self) -> T;
+}
+
+macro_rules! impl_widen {
+    ($($from:ty => $($to:ty),+;)+) => {
+        $($(
+            impl WidenInfallible<$to> for $from {
+                #[inline]
+                fn widen_infallible(self) -> $to {
+                    self as $to
+                }
+            }
+        )+)+
+    };
+}
+
+impl_widen! {
+    u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize;
+    u16 => u32, u64, u128, usize, i32, i64, i128;
+    u32 => u64, u128, i64, i128;
+    u64 => u128, i128;
+    i8 => i16, i32, i64, i128, isize;
+    i16 => i32, i64, i128, isize;
+    i32 => i64, i128;
+    i64 => i128;
+    usize => u128;
+    isize => i128;
+}
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64"
+))]
+impl_widen! {
+    usize => u64, i128;
+    isize => i64;
+}
+
+#[cfg(any(target_pointer_width = "16", target_pointer_width = "32"))]
+impl_widen! {
+    usize => u32, i64;
+    isize => i32;
+}
+
+#[cfg(target_pointer_width = "16")]
+impl_widen! {
+    usize => u16, i32;
+    isize => i16;
+}
+
+#[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+impl_widen! {
+    u16 => isize;
+    u32 => usize;
+    i32 => isize;
+}
+
+#[cfg(target_pointer_width = "64")]
+impl_widen! {
+    u32 => isize;
+    u64 => usize;
+    i64 => isize;
+}
@@ -1,6 +1,7 @@
 #![cfg(feature = "blanket_impl")]
 #![feature(never_type)]
 
+use std::convert::Infallible;
 use unwrap_infallible::UnwrapInfallible;
 
 enum MyNeverToken {}
@@ -16,3 +17,17 @@ fn with_custom_type() {
     let r: Result<bool, MyNeverToken> = Ok(true);
     assert!(r.unwrap_infallible());
 }
+
+struct Wrapper(Infallible);
+
+impl From<Wrapper> for ! {
+    fn from(wrapper: Wrapper) -> Self {
+        match wrapper.0 {}
+    }
+}
+
+#[test]
+fn with_type_containing_infallible() {
+    let r: Result<u8, Wrapper> = Ok(42);
+    assert_eq!(r.unwrap_infallible(), 42);
+}
@@ -0,0 +1,78 @@
+use std::convert::Infallible;
+use unwrap_infallible::{Uninhabited, UnwrapInfallible};
+
+// An uninhabited type that is not zero-sized, so that results with it
+// are laid out differently from their success values.
+struct Tagged {
+    _tag: u64,
+    never: Infallible,
+}
+
+impl Uninhabited for Tagged {
+    fn absurd<T>(self) -> T {
+        self.never.absurd()
+    }
+}
+
+#[test]
+fn array() {
+    let a: [Result<u32, Infallible>; 3] = [Ok(1), Ok(2), Ok(3)];
+    assert_eq!(a.unwrap_infallible(), [1, 2, 3]);
+    let a: [Result<u8, Tagged>; 3] = [Ok(1), Ok(2), Ok(3)];
+    assert_eq!(a.unwrap_infallible(), [1, 2, 3]);
+}
+
+#[cfg(feature = "alloc")]
+mod alloc {
+    use super::Tagged;
+    use std::borrow::Cow;
+    use std::convert::Infallible;
+    use unwrap_infallible::UnwrapInfallible;
+
+    #[test]
+    fn vec_reuses_allocation() {
+        let v: Vec<Result<String, Infallible>> = vec![Ok("a".into()), Ok("b".into())];
+        let ptr = v.as_ptr() as *const u8;
+        let v = v.unwrap_infallible();
+        assert_eq!(v, ["a", "b"]);
+        assert_eq!(v.as_ptr() as *const u8, ptr);
+    }
+
+    #[test]
+    fn vec_with_different_layout() {
+        let v: Vec<Result<u8, Tagged>> = vec![Ok(1), Ok(2)];
+        assert_eq!(v.unwrap_infallible(), [1, 2]);
+    }
+
+    #[test]
+    fn boxes() {
+        let b: Box<[Result<String, Infallible>]> = vec![Ok("a".into())].into_boxed_slice();
+        assert_eq!(&*b.unwrap_infallible(), ["a"]);
+        let b: Box<Result<String, Infallible>> = Box::new(Ok("a".into()));
+        assert_eq!(*b.unwrap_infallible(), "a");
+        let b: Box<Result<u8, Tagged>> = Box::new(Ok(1));
+        assert_eq!(*b.unwrap_infallible(), 1);
+    }
+
+    #[test]
+    fn slice() {
+        let v: Vec<Result<u32, Infallible>> = vec![Ok(1), Ok(2)];
+        match v.as_slice().unwrap_infallible() {
+            Cow::Borrowed(s) => assert_eq!(s, [1, 2]),
+            Cow::Owned(_) => panic!("expected a borrowed slice"),
+        }
+        let v: Vec<Result<u8, Tagged>> = vec![Ok(1), Ok(2)];
+        assert_eq!(*v.as_slice().unwrap_infallible(), [1, 2]);
+    }
+}
+
+#[test]
+fn slice_of_non_clone_by_reference() {
+    use unwrap_infallible::InfallibleIteratorExt;
+
+    struct NotClone(u32);
+
+    let v: Vec<Result<NotClone, Infallible>> = vec![Ok(NotClone(1)), Ok(NotClone(2))];
+    let sum: u32 = v.iter().unwrap_infallible_each().map(|x| x.0).sum();
+    assert_eq!(sum, 3);
+}
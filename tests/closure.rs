@@ -0,0 +1,55 @@
+use std::cell::OnceCell;
+use unwrap_infallible::{ok_fn0, ok_fn1, ok_fn2, UnwrapInfallible};
+
+fn get_or_try_init<E>(
+    cell: &OnceCell<u32>,
+    init: impl FnOnce() -> Result<u32, E>,
+) -> Result<&u32, E> {
+    match cell.get() {
+        Some(v) => Ok(v),
+        None => {
+            let v = init()?;
+            Ok(cell.get_or_init(|| v))
+        }
+    }
+}
+
+#[test]
+fn lift_nullary() {
+    let cell = OnceCell::new();
+    let name = String::from("answer");
+    let v = get_or_try_init(&cell, ok_fn0(move || name.len() as u32 * 7)).unwrap_infallible();
+    assert_eq!(*v, 42);
+}
+
+#[test]
+fn lift_unary() {
+    let r = [1, 2, 3]
+        .iter()
+        .try_for_each(ok_fn1(|x: &i32| assert!(*x > 0)));
+    r.unwrap_infallible();
+    let r: Result<Vec<_>, _> = [1, 2, 3].iter().map(ok_fn1(|x| x + 1)).collect();
+    assert_eq!(r.unwrap_infallible(), [2, 3, 4]);
+}
+
+#[test]
+fn lift_binary() {
+    let r = [1, 2, 3].iter().try_fold(1, ok_fn2(|acc, x| acc * x));
+    assert_eq!(r.unwrap_infallible(), 6);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn lift_for_rayon() {
+    use rayon::prelude::*;
+
+    let r = (1..=3u32)
+        .into_par_iter()
+        .try_for_each(ok_fn1(|x| assert!(x > 0)));
+    r.unwrap_infallible();
+    let r = (1..=3u32)
+        .into_par_iter()
+        .map(ok_fn1(|x| x * 2))
+        .try_reduce(|| 0, ok_fn2(|a, b| a + b));
+    assert_eq!(r.unwrap_infallible(), 12);
+}
@@ -0,0 +1,38 @@
+use std::convert::Infallible;
+use unwrap_infallible::UnwrapInfallible;
+
+#[test]
+fn tuple() {
+    let r: Result<bool, (Infallible, u32)> = Ok(true);
+    assert!(r.unwrap_infallible());
+    let r: Result<bool, (Infallible, u32)> = Ok(true);
+    assert!(*(&r).unwrap_infallible());
+}
+
+#[test]
+fn array() {
+    let r: Result<bool, [Infallible; 1]> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[test]
+fn result() {
+    let r: Result<bool, Result<Infallible, Infallible>> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn boxed() {
+    let r: Result<bool, Box<Infallible>> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn either() {
+    use either::Either;
+
+    let r: Result<bool, Either<Infallible, (Infallible, u8)>> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
@@ -0,0 +1,23 @@
+use unwrap_infallible::{Uninhabited, UnwrapInfallible};
+
+enum MyNeverToken {}
+
+impl Uninhabited for MyNeverToken {
+    fn absurd<T>(self) -> T {
+        match self {}
+    }
+}
+
+#[test]
+fn with_custom_type() {
+    let r: Result<bool, MyNeverToken> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[test]
+fn by_reference() {
+    let mut r: Result<u32, MyNeverToken> = Ok(42);
+    assert_eq!(*(&r).unwrap_infallible(), 42);
+    *(&mut r).unwrap_infallible() += 1;
+    assert_eq!(r.unwrap_infallible(), 43);
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "embedded-hal")]
+
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin, PinState, StatefulOutputPin};
+use std::convert::Infallible;
+use unwrap_infallible::{InfallibleInputPin, InfallibleOutputPin, InfallibleStatefulOutputPin};
+
+struct Pin(bool);
+
+impl ErrorType for Pin {
+    type Error = Infallible;
+}
+
+impl OutputPin for Pin {
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        self.0 = false;
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        self.0 = true;
+        Ok(())
+    }
+}
+
+impl StatefulOutputPin for Pin {
+    fn is_set_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.0)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.0)
+    }
+}
+
+impl InputPin for Pin {
+    fn is_high(&mut self) -> Result<bool, Infallible> {
+        Ok(self.0)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Infallible> {
+        Ok(!self.0)
+    }
+}
+
+#[test]
+fn output() {
+    let mut pin = Pin(false);
+    pin.set_high_infallible();
+    assert!(pin.0);
+    pin.set_low_infallible();
+    assert!(!pin.0);
+    pin.set_state_infallible(PinState::High);
+    assert!(pin.0);
+}
+
+#[test]
+fn stateful_output() {
+    let mut pin = Pin(false);
+    pin.toggle_infallible();
+    assert!(pin.is_set_high_infallible());
+    pin.toggle_infallible();
+    assert!(pin.is_set_low_infallible());
+}
+
+#[test]
+fn input() {
+    let mut pin = Pin(true);
+    assert!(pin.is_high_infallible());
+    assert!(!pin.is_low_infallible());
+}
+
+#[test]
+fn by_reference() {
+    fn blink(mut led: impl OutputPin<Error = Infallible>) {
+        led.set_high_infallible();
+    }
+
+    let mut pin = Pin(false);
+    blink(&mut pin);
+    assert!(pin.0);
+}
+
+#[test]
+fn generic() {
+    fn blink<P: OutputPin<Error = Infallible>>(pin: &mut P) {
+        pin.set_high_infallible();
+        pin.set_low_infallible();
+    }
+
+    let mut pin = Pin(true);
+    blink(&mut pin);
+    assert!(!pin.0);
+}
@@ -0,0 +1,129 @@
+#![cfg(any(feature = "embedded-io", feature = "embedded-io-async"))]
+
+use std::convert::Infallible;
+
+struct Buf {
+    data: Vec<u8>,
+    pos: usize,
+}
+
+impl Buf {
+    fn new(data: &[u8]) -> Self {
+        Buf {
+            data: data.to_vec(),
+            pos: 0,
+        }
+    }
+
+    fn read_bytes(&mut self, buf: &mut [u8]) -> usize {
+        let n = buf.len().min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        n
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+mod blocking {
+    use super::*;
+    use embedded_io::{ErrorType, Read, ReadExactError, Write};
+    use unwrap_infallible::{InfallibleEmbeddedRead, InfallibleEmbeddedWrite};
+
+    impl ErrorType for Buf {
+        type Error = Infallible;
+    }
+
+    impl Read for Buf {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+            Ok(self.read_bytes(buf))
+        }
+    }
+
+    impl Write for Buf {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write() {
+        let mut buf = Buf::new(b"");
+        assert_eq!(buf.write_infallible(b"it's "), 5);
+        buf.write_all_infallible(b"always sunny!");
+        buf.flush_infallible();
+        assert_eq!(buf.data, b"it's always sunny!");
+    }
+
+    #[test]
+    fn read() {
+        let mut buf = Buf::new(b"sunny");
+        let mut out = [0; 3];
+        assert_eq!(buf.read_infallible(&mut out), 3);
+        assert_eq!(&out, b"sun");
+        assert!(matches!(
+            buf.read_exact_infallible(&mut out),
+            Err(ReadExactError::UnexpectedEof)
+        ));
+    }
+}
+
+#[cfg(feature = "embedded-io-async")]
+mod nonblocking {
+    use super::*;
+    use embedded_io_async::{ErrorType, Read, ReadExactError, Write};
+    use futures::executor::block_on;
+    use unwrap_infallible::{InfallibleEmbeddedAsyncRead, InfallibleEmbeddedAsyncWrite};
+
+    struct AsyncBuf(Buf);
+
+    impl ErrorType for AsyncBuf {
+        type Error = Infallible;
+    }
+
+    impl Read for AsyncBuf {
+        async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+            Ok(self.0.read_bytes(buf))
+        }
+    }
+
+    impl Write for AsyncBuf {
+        async fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+            self.0.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        async fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn write() {
+        block_on(async {
+            let mut buf = AsyncBuf(Buf::new(b""));
+            assert_eq!(buf.write_infallible(b"it's ").await, 5);
+            buf.write_all_infallible(b"always sunny!").await;
+            buf.flush_infallible().await;
+            assert_eq!(buf.0.data, b"it's always sunny!");
+        });
+    }
+
+    #[test]
+    fn read() {
+        block_on(async {
+            let mut buf = AsyncBuf(Buf::new(b"sunny"));
+            let mut out = [0; 3];
+            assert_eq!(buf.read_infallible(&mut out).await, 3);
+            assert_eq!(&out, b"sun");
+            assert!(matches!(
+                buf.read_exact_infallible(&mut out).await,
+                Err(ReadExactError::UnexpectedEof)
+            ));
+        });
+    }
+}
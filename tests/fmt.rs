@@ -0,0 +1,53 @@
+#![cfg(feature = "alloc")]
+
+use std::fmt;
+use unwrap_infallible::{write_infallible, writeln_infallible, InfallibleFmtWrite};
+
+#[test]
+fn macros() {
+    let mut s = String::new();
+    let weather = "sunny";
+    write_infallible!(s, "it's always {weather}");
+    writeln_infallible!(s, "!");
+    writeln_infallible!(&mut s);
+    assert_eq!(s, "it's always sunny!\n\n");
+}
+
+#[test]
+fn by_reference() {
+    fn write_greeting<W: InfallibleFmtWrite>(mut w: W) {
+        w.write_char_infallible('h');
+        w.write_str_infallible("ello");
+    }
+
+    let mut s = String::new();
+    write_greeting(&mut s);
+    write_greeting(&mut s);
+    assert_eq!(s, "hellohello");
+}
+
+#[test]
+#[should_panic(
+    expected = "a formatting trait implementation returned an error to an infallible writer"
+)]
+fn bogus_display() {
+    struct Bogus;
+
+    impl fmt::Display for Bogus {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    write_infallible!(String::new(), "{}", Bogus);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn io_writer() {
+    use unwrap_infallible::InfallibleWrite;
+
+    let mut buf = Vec::new();
+    writeln_infallible!(buf, "{}", 42);
+    assert_eq!(buf, b"42\n");
+}
@@ -0,0 +1,28 @@
+use std::convert::Infallible;
+use std::future::{self, Future};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use unwrap_infallible::{InfallibleFutureExt, UnwrapInfallible};
+
+fn poll_once<F: Future>(fut: F) -> Poll<F::Output> {
+    let fut = pin!(fut);
+    fut.poll(&mut Context::from_waker(Waker::noop()))
+}
+
+#[test]
+fn future() {
+    let fut = future::ready(Ok::<_, Infallible>(42)).unwrap_infallible();
+    assert_eq!(poll_once(fut), Poll::Ready(42));
+}
+
+#[test]
+fn pending() {
+    let fut = future::pending::<Result<u32, Infallible>>().unwrap_infallible();
+    assert_eq!(poll_once(fut), Poll::Pending);
+}
+
+#[test]
+fn poll() {
+    let p: Poll<Result<u32, Infallible>> = Poll::Ready(Ok(42));
+    assert_eq!(p.unwrap_infallible(), Poll::Ready(42));
+}
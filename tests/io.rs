@@ -0,0 +1,47 @@
+#![cfg(feature = "std")]
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use unwrap_infallible::InfallibleWrite;
+
+#[test]
+fn vec_deque() {
+    let mut buf = VecDeque::new();
+    buf.write_all_infallible(b"sunny");
+    buf.write_fmt_infallible(format_args!(" {}", 42));
+    assert_eq!(buf, b"sunny 42");
+}
+
+#[test]
+fn sink() {
+    io::sink().write_all_infallible(b"sunny");
+}
+
+#[test]
+fn by_reference() {
+    fn write_greeting<W: InfallibleWrite>(mut w: W) {
+        w.write_all_infallible(b"hello");
+    }
+
+    let mut buf = Vec::new();
+    write_greeting(&mut buf);
+    write_greeting(&mut buf);
+    assert_eq!(buf, b"hellohello");
+}
+
+#[test]
+#[should_panic(
+    expected = "a formatting trait implementation returned an error to an infallible writer"
+)]
+fn bogus_display() {
+    struct Bogus;
+
+    impl fmt::Display for Bogus {
+        fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+            Err(fmt::Error)
+        }
+    }
+
+    Vec::new().write_fmt_infallible(format_args!("{}", Bogus));
+}
@@ -0,0 +1,28 @@
+use std::convert::{Infallible, TryFrom};
+use std::iter::FusedIterator;
+use unwrap_infallible::InfallibleIteratorExt;
+
+fn assert_fused<I: FusedIterator>(_: &I) {}
+
+#[test]
+fn adapter() {
+    let mut iter = [1u8, 2, 3]
+        .iter()
+        .copied()
+        .map(u32::try_from)
+        .unwrap_infallible_each();
+    assert_fused(&iter);
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+    assert_eq!(iter.collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn collapse() {
+    let results = || vec![Ok::<u32, Infallible>(2), Ok(3)].into_iter();
+    assert_eq!(results().collect_infallible::<Vec<_>>(), [2, 3]);
+    assert_eq!(results().sum_infallible::<u32>(), 5);
+    assert_eq!(results().product_infallible::<u32>(), 6);
+}
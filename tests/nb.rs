@@ -0,0 +1,51 @@
+#![cfg(feature = "nb")]
+
+use std::convert::Infallible;
+use std::task::Poll;
+use unwrap_infallible::{block_infallible, NbInfallibleExt, Uninhabited};
+
+struct Timer {
+    remaining: u32,
+}
+
+impl Timer {
+    fn wait(&mut self) -> nb::Result<(), Infallible> {
+        if self.remaining == 0 {
+            Ok(())
+        } else {
+            self.remaining -= 1;
+            Err(nb::Error::WouldBlock)
+        }
+    }
+}
+
+#[test]
+fn into_poll() {
+    let mut timer = Timer { remaining: 1 };
+    assert_eq!(timer.wait().into_poll_infallible(), Poll::Pending);
+    assert_eq!(timer.wait().into_poll_infallible(), Poll::Ready(()));
+}
+
+#[test]
+fn block() {
+    let mut timer = Timer { remaining: 5 };
+    block_infallible!(timer.wait());
+    assert_eq!(timer.remaining, 0);
+}
+
+#[test]
+fn custom_uninhabited() {
+    enum Never {}
+
+    impl Uninhabited for Never {
+        fn absurd<T>(self) -> T {
+            match self {}
+        }
+    }
+
+    fn read() -> nb::Result<u8, Never> {
+        Ok(42)
+    }
+
+    assert_eq!(block_infallible!(read()), 42);
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicU32, Ordering};
+use unwrap_infallible::InfallibleParallelIteratorExt;
+
+#[test]
+fn unwrap_each_indexed() {
+    let v: Vec<Result<u32, Infallible>> = vec![Ok(1), Ok(2), Ok(3)];
+    let iter = v.into_par_iter().unwrap_infallible_each();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), [3, 2, 1]);
+}
+
+#[test]
+fn try_for_each() {
+    let sum = AtomicU32::new(0);
+    (1..=3u32).into_par_iter().try_for_each_infallible(|x| {
+        sum.fetch_add(x, Ordering::Relaxed);
+        Ok::<_, Infallible>(())
+    });
+    assert_eq!(sum.into_inner(), 6);
+}
+
+#[test]
+fn try_fold() {
+    let sum: u32 = (1..=100u32)
+        .into_par_iter()
+        .try_fold_infallible(|| 0, |acc, x| Ok::<_, Infallible>(acc + x))
+        .sum();
+    assert_eq!(sum, 5050);
+}
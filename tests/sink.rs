@@ -0,0 +1,26 @@
+#![cfg(feature = "futures-sink")]
+
+use futures::executor::block_on;
+use futures::sink;
+use unwrap_infallible::InfallibleSinkExt;
+
+#[test]
+fn drain() {
+    block_on(async {
+        let mut sink = sink::drain();
+        sink.feed_infallible(1).await;
+        sink.flush_infallible().await;
+        sink.send_infallible(2).await;
+        sink.close_infallible().await;
+    });
+}
+
+#[test]
+fn vec() {
+    let mut v = Vec::new();
+    block_on(async {
+        v.send_infallible(1).await;
+        v.feed_infallible(2).await;
+    });
+    assert_eq!(v, [1, 2]);
+}
@@ -0,0 +1,20 @@
+#![cfg(feature = "futures-core")]
+
+use futures::executor::block_on;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::convert::Infallible;
+use unwrap_infallible::InfallibleStreamExt;
+
+#[test]
+fn unwrap_each() {
+    let s = stream::iter(vec![Ok::<_, Infallible>(1), Ok(2)]).unwrap_infallible_each();
+    assert_eq!(s.size_hint(), (2, Some(2)));
+    assert_eq!(block_on(s.collect::<Vec<_>>()), [1, 2]);
+}
+
+#[test]
+fn into_try_stream() {
+    let s = stream::iter(vec![1, 2]).into_try_stream();
+    let v: Result<Vec<_>, Infallible> = block_on(s.try_collect());
+    assert_eq!(v, Ok(vec![1, 2]));
+}
@@ -0,0 +1,32 @@
+use unwrap_infallible::WidenInfallible;
+
+#[test]
+fn fixed_size() {
+    let a: u64 = 200u8.widen_infallible();
+    assert_eq!(a, 200);
+    let b: i32 = u16::MAX.widen_infallible();
+    assert_eq!(b, 65535);
+    let c: i128 = i64::MIN.widen_infallible();
+    assert_eq!(c, i64::MIN as i128);
+}
+
+#[test]
+fn pointer_sized() {
+    let a: u64 = usize::MAX.widen_infallible();
+    assert_eq!(a, usize::MAX as u64);
+    let b: i64 = isize::MIN.widen_infallible();
+    assert_eq!(b, isize::MIN as i64);
+    let c: usize = u16::MAX.widen_infallible();
+    assert_eq!(c, 65535);
+}
+
+#[cfg(target_pointer_width = "64")]
+#[test]
+fn pointer_sized_64() {
+    let a: usize = u64::MAX.widen_infallible();
+    assert_eq!(a, usize::MAX);
+    let b: isize = u32::MAX.widen_infallible();
+    assert_eq!(b, 0xffff_ffff);
+    let c: isize = i64::MIN.widen_infallible();
+    assert_eq!(c, isize::MIN);
+}
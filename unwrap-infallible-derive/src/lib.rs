@@ -0,0 +1,169 @@
+//! Derive macro for the `Uninhabited` trait
+//!
+//! This crate provides `#[derive(Uninhabited)]` for the trait of the same
+//! name defined in crate `unwrap-infallible`. It is normally used through
+//! the `derive` feature of that crate, which re-exports the macro.
+//!
+//! The trait can be derived for:
+//!
+//! * enums with no variants;
+//! * enums where every variant has a field of an uninhabited type;
+//! * structs with a field of an uninhabited type.
+//!
+//! If a variant or a struct has more than one field, the uninhabited field
+//! must be marked with the `#[uninhabited]` attribute.
+//! The derived implementation is bounded on the types of the selected
+//! fields implementing `Uninhabited`, so generic parameters are accepted.
+//!
+//! ```
+//! use unwrap_infallible::{Uninhabited, UnwrapInfallible};
+//!
+//! #[derive(Uninhabited)]
+//! enum Never {}
+//!
+//! #[derive(Uninhabited)]
+//! enum MyError<E = Never> {
+//!     Io(E),
+//!     Parse {
+//!         line: u32,
+//!         #[uninhabited]
+//!         reason: E,
+//!     },
+//! }
+//!
+//! let r: Result<u32, MyError> = Ok(42);
+//! assert_eq!(r.unwrap_infallible(), 42);
+//! ```
+//!
+//! Types that can have values are rejected:
+//!
+//! ```compile_fail
+//! use unwrap_infallible::Uninhabited;
+//!
+//! #[derive(Uninhabited)]
+//! enum NotReally {
+//!     Unit,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use unwrap_infallible::Uninhabited;
+//!
+//! #[derive(Uninhabited)]
+//! enum NotReally {
+//!     Value(u32),
+//! }
+//! ```
+
+#![warn(rust_2018_idioms)]
+#![warn(clippy::all)]
+#![warn(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, Member};
+
+/// Derives the `Uninhabited` trait.
+///
+/// See the [crate documentation](crate) for the supported types.
+#[proc_macro_derive(Uninhabited, attributes(uninhabited))]
+pub fn derive_uninhabited(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let mut patterns = Vec::new();
+    let mut field_types = Vec::new();
+    match &input.data {
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                let variant_ident = &variant.ident;
+                let (member, field) = uninhabited_field(&variant.fields, variant.span())?;
+                patterns.push((quote!(#ident::#variant_ident), member));
+                field_types.push(&field.ty);
+            }
+        }
+        Data::Struct(data) => {
+            let (member, field) = uninhabited_field(&data.fields, ident.span())?;
+            patterns.push((quote!(#ident), member));
+            field_types.push(&field.ty);
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`Uninhabited` cannot be derived for unions",
+            ));
+        }
+    }
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in field_types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::unwrap_infallible::Uninhabited));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let arms = patterns.iter().map(|(path, member)| {
+        quote! {
+            #path { #member: __never, .. } => {
+                ::unwrap_infallible::Uninhabited::absurd(__never)
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics ::unwrap_infallible::Uninhabited for #ident #ty_generics #where_clause {
+            fn absurd<__T>(self) -> __T {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn uninhabited_field(fields: &Fields, span: Span) -> syn::Result<(Member, &Field)> {
+    let mut marked = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|a| a.path().is_ident("uninhabited")));
+    let (index, field) = match (marked.next(), marked.next()) {
+        (Some(found), None) => found,
+        (Some(_), Some((_, extra))) => {
+            return Err(syn::Error::new(
+                extra.span(),
+                "only one field can be marked `#[uninhabited]`",
+            ));
+        }
+        (None, _) => {
+            let mut iter = fields.iter();
+            match (iter.next(), iter.next()) {
+                (Some(field), None) => (0, field),
+                (None, _) => {
+                    return Err(syn::Error::new(
+                        span,
+                        "cannot derive `Uninhabited`: \
+                         a value with no fields can always be constructed",
+                    ));
+                }
+                (Some(_), Some(_)) => {
+                    return Err(syn::Error::new(
+                        span,
+                        "the uninhabited field must be marked with `#[uninhabited]`",
+                    ));
+                }
+            }
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(index.into()),
+    };
+    Ok((member, field))
+}
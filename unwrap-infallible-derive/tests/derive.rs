@@ -0,0 +1,49 @@
+#![allow(dead_code)]
+
+use unwrap_infallible::{Uninhabited, UnwrapInfallible};
+
+#[derive(Uninhabited)]
+enum MyNeverToken {}
+
+#[derive(Uninhabited)]
+enum MyError<E = MyNeverToken> {
+    Io(E),
+    Parse(E),
+}
+
+#[derive(Uninhabited)]
+struct Tagged<E> {
+    _tag: &'static str,
+    #[uninhabited]
+    _never: E,
+}
+
+#[test]
+fn empty_enum() {
+    let r: Result<bool, MyNeverToken> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[test]
+fn all_variants_uninhabited() {
+    let r: Result<bool, MyError> = Ok(true);
+    assert!(r.unwrap_infallible());
+    let r: Result<bool, MyError<MyNeverToken>> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[test]
+fn struct_with_uninhabited_field() {
+    let r: Result<bool, Tagged<MyError>> = Ok(true);
+    assert!(r.unwrap_infallible());
+}
+
+#[test]
+fn by_reference() {
+    let mut r: Result<u32, MyError> = Ok(42);
+    assert_eq!(*(&r).unwrap_infallible(), 42);
+    *(&mut r).unwrap_infallible() += 1;
+    assert_eq!(r.unwrap_infallible(), 43);
+    let r: Result<u32, MyNeverToken> = Ok(42);
+    assert_eq!(*(&r).unwrap_infallible(), 42);
+}